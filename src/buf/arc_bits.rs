@@ -0,0 +1,105 @@
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+
+use crate::prelude::*;
+
+/// A cheaply-cloneable, `Arc`-backed bit buffer.
+///
+/// `ArcBits` pairs a shared, reference-counted backing allocation with a
+/// `bit_start`/`bit_len` window over it, mirroring the windowing
+/// [`Bits`]/[`BitsMut`] already use, but letting many overlapping views
+/// share the same allocation instead of duplicating the payload. Slicing via
+/// [`ArcBits::slice`] is `O(1)`: it just narrows the window and bumps the
+/// `Arc`'s reference count.
+#[derive(Clone, Debug)]
+pub struct ArcBits {
+    inner: Arc<[u8]>,
+    bit_start: usize,
+    bit_len: usize,
+}
+
+impl ArcBits {
+    /// Wraps `data` as an `ArcBits` spanning its entire length.
+    pub fn new(data: Arc<[u8]>) -> Self {
+        let bit_len = data.len() * 8;
+        ArcBits {
+            inner: data,
+            bit_start: 0,
+            bit_len,
+        }
+    }
+
+    /// Returns a new `ArcBits` over the given `bits` range of this buffer,
+    /// sharing the same backing allocation at no copy cost.
+    pub fn slice<R: RangeBounds<usize>>(&self, bits: R) -> ArcBits {
+        let start = match bits.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match bits.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.bit_len,
+        };
+        assert!(start <= end && end <= self.bit_len, "slice out of bounds");
+        ArcBits {
+            inner: self.inner.clone(),
+            bit_start: self.bit_start + start,
+            bit_len: end - start,
+        }
+    }
+}
+
+impl BorrowBits for ArcBits {
+    fn borrow_bits(&self) -> &BitSlice {
+        &BitSlice::from_slice(&self.inner)[self.bit_start..self.bit_start + self.bit_len]
+    }
+}
+
+impl BitBuf for ArcBits {
+    fn advance(&mut self, count: usize) {
+        assert!(count <= self.remaining(), "advance past end of ArcBits");
+        self.bit_start += count;
+        self.bit_len -= count;
+    }
+
+    fn remaining(&self) -> usize {
+        self.bit_len
+    }
+
+    fn chunk(&self) -> &BitSlice {
+        self.borrow_bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arc_bits_advance() {
+        let mut bits = ArcBits::new(Arc::from(vec![0b11110000, 0b00001111]));
+
+        bits.advance(4);
+        assert_eq!(bits.chunk()[..4], bits![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_arc_bits_slice_shares_allocation() {
+        let data: Arc<[u8]> = Arc::from(vec![0b11110000, 0b00001111]);
+        let bits = ArcBits::new(data.clone());
+
+        let sub = bits.slice(4..12);
+        assert_eq!(sub.chunk(), bits![0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Arc::strong_count(&data), 3);
+    }
+
+    #[test]
+    fn test_arc_bits_clone_is_cheap() {
+        let bits = ArcBits::new(Arc::from(vec![0xAAu8]));
+        let cloned = bits.clone();
+        assert!(Arc::ptr_eq(&bits.inner, &cloned.inner));
+        assert_eq!(bits.chunk(), cloned.chunk());
+    }
+}
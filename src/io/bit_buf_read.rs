@@ -0,0 +1,83 @@
+use crate::prelude::*;
+
+/// Bit-level analogue of [`std::io::BufRead`].
+///
+/// Lets callers inspect the not-yet-read tail of a bit source without
+/// borrowing the whole buffer the way [`BitCursor::split`] does, so they can
+/// peek ahead (e.g. to branch on a variable-length prefix code) and then
+/// commit exactly as many bits as they used.
+pub trait BitBufRead {
+    /// Returns the bits that have not yet been read.
+    fn remaining_bits(&self) -> &BitSlice;
+
+    /// Returns the bits that have not yet been read, without advancing the
+    /// position. Provided for symmetry with [`std::io::BufRead::fill_buf`];
+    /// equivalent to [`BitBufRead::remaining_bits`].
+    fn fill_bits(&mut self) -> &BitSlice;
+
+    /// Marks `amt` bits as read, advancing the position.
+    fn consume_bits(&mut self, amt: usize);
+
+    /// Returns `true` if there are no more bits to read.
+    fn is_empty(&self) -> bool;
+}
+
+impl<T> BitBufRead for BitCursor<T>
+where
+    T: BorrowBits,
+{
+    fn remaining_bits(&self) -> &BitSlice {
+        let bits = self.get_ref().borrow_bits();
+        let pos = (self.position() as usize).min(bits.len());
+        &bits[pos..]
+    }
+
+    fn fill_bits(&mut self) -> &BitSlice {
+        self.remaining_bits()
+    }
+
+    fn consume_bits(&mut self, amt: usize) {
+        let pos = self.position();
+        let len = self.get_ref().borrow_bits().len() as u64;
+        assert!(pos + amt as u64 <= len, "consume past end of buffer");
+        self.set_position(pos + amt as u64);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position() >= self.get_ref().borrow_bits().len() as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_remaining_bits() {
+        let data = BitVec::from_vec(vec![0b11110000, 0b00001111]);
+        let mut cursor = BitCursor::new(data);
+        cursor.set_position(4);
+
+        assert_eq!(cursor.remaining_bits().len(), 12);
+        assert_eq!(cursor.remaining_bits()[..4], bits![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_consume_bits() {
+        let data = BitVec::from_vec(vec![0b11110000]);
+        let mut cursor = BitCursor::new(data);
+
+        assert!(!cursor.is_empty());
+        cursor.consume_bits(8);
+        assert!(cursor.is_empty());
+        assert_eq!(cursor.remaining_bits().len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_consume_bits_past_end_panics() {
+        let data = BitVec::from_vec(vec![0u8; 1]);
+        let mut cursor = BitCursor::new(data);
+        cursor.consume_bits(9);
+    }
+}
@@ -0,0 +1,211 @@
+use std::io::{SeekFrom, Write};
+
+use crate::prelude::*;
+
+/// Adapter that chains two bit sources together, exposing them as a single
+/// contiguous bitstream.
+///
+/// Created by calling [`BitCursor::chain`]. Reads draw from `first` until it
+/// is exhausted and then continue into `second` starting at its bit 0;
+/// `bit_seek` maps a global bit offset onto whichever segment it falls in.
+/// Chaining more than two sources works the same way `std::io::Chain` does:
+/// nest another `chain` call, e.g. `a.chain(b).chain(c)`.
+///
+/// Deliberately implements only the bit-level [`BitRead`]/[`BitWrite`]/
+/// [`BitSeek`] traits, not the byte-level [`std::io::Read`]/[`std::io::Write`]/
+/// [`std::io::Seek`]. `first` isn't guaranteed to end on a byte boundary, so
+/// any byte-granularity operation risks the same hazard: a byte-level read
+/// would silently pad a trailing partial byte out of `first` and invent bits
+/// at the seam, and a byte-level seek (`n * 8`) can just as easily land
+/// mid-segment. Byte-level I/O over a `BitChain` should go through
+/// [`BitCursor`]'s own `Read`/`Write`/`Seek` impls on each segment, or accept
+/// the bit-level traits here.
+#[derive(Debug)]
+pub struct BitChain<A, B> {
+    first: BitCursor<A>,
+    second: BitCursor<B>,
+    current_segment: usize,
+}
+
+impl<T> BitCursor<T> {
+    /// Chains this cursor with `other`, creating a [`BitChain`] that
+    /// reads/writes/seeks across both as if they were one contiguous
+    /// bitstream.
+    pub fn chain<U>(self, other: U) -> BitChain<T, U> {
+        BitChain {
+            first: self,
+            second: BitCursor::new(other),
+            current_segment: 0,
+        }
+    }
+}
+
+impl<A, B> BitChain<A, B> {
+    /// Consumes the `BitChain`, returning the wrapped sources.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first.into_inner(), self.second.into_inner())
+    }
+
+    /// Gets references to the underlying sources in this `BitChain`.
+    pub fn get_ref(&self) -> (&A, &B) {
+        (self.first.get_ref(), self.second.get_ref())
+    }
+
+    /// Gets mutable references to the underlying sources in this `BitChain`.
+    pub fn get_mut(&mut self) -> (&mut A, &mut B) {
+        (self.first.get_mut(), self.second.get_mut())
+    }
+}
+
+impl<A, B> BitChain<A, B>
+where
+    A: BorrowBits,
+    B: BorrowBits,
+{
+    /// Returns the total length, in bits, of both segments combined,
+    /// regardless of the current position.
+    pub fn remaining(&self) -> u64 {
+        self.first.get_ref().borrow_bits().len() as u64 + self.second.get_ref().borrow_bits().len() as u64
+    }
+
+    /// Returns `true` if both segments are empty.
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+impl<A, B> BitSeek for BitChain<A, B>
+where
+    A: BorrowBits,
+    B: BorrowBits,
+{
+    fn bit_seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let first_len = self.first.get_ref().borrow_bits().len() as u64;
+        let total_len = self.remaining();
+
+        let target = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::End(n) => total_len.checked_add_signed(n),
+            SeekFrom::Current(n) => {
+                let cur = if self.current_segment == 0 {
+                    self.first.position()
+                } else {
+                    first_len + self.second.position()
+                };
+                cur.checked_add_signed(n)
+            }
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overlfowing position",
+            )
+        })?;
+        // Mirror `std::io::Cursor`: seeking past the end is allowed, it just
+        // parks the position at the end rather than panicking on the next
+        // read. Clamp here rather than letting it flow into the sub-cursors,
+        // since one of their lengths would otherwise be exceeded directly.
+        let target = target.min(total_len);
+
+        if target < first_len {
+            self.current_segment = 0;
+            self.first.bit_seek(SeekFrom::Start(target))?;
+            self.second.bit_seek(SeekFrom::Start(0))?;
+        } else {
+            self.current_segment = 1;
+            self.first.bit_seek(SeekFrom::Start(first_len))?;
+            self.second.bit_seek(SeekFrom::Start(target - first_len))?;
+        }
+        Ok(target)
+    }
+}
+
+impl<A, B> BitRead for BitChain<A, B>
+where
+    A: BorrowBits,
+    B: BorrowBits,
+{
+    fn read_bits<O: BitStore>(&mut self, dest: &mut BitSlice<O>) -> std::io::Result<usize> {
+        let mut total = 0;
+        if self.current_segment == 0 {
+            total += self.first.read_bits(dest)?;
+            if total < dest.len() {
+                self.current_segment = 1;
+                total += self.second.read_bits(&mut dest[total..])?;
+            }
+            return Ok(total);
+        }
+        self.second.read_bits(dest)
+    }
+}
+
+impl<A, B> BitWrite for BitChain<A, B>
+where
+    A: BorrowBitsMut,
+    B: BorrowBitsMut,
+    BitCursor<A>: Write,
+    BitCursor<B>: Write,
+{
+    fn write_bits<O: BitStore>(&mut self, source: &BitSlice<O>) -> std::io::Result<usize> {
+        let mut total = 0;
+        if self.current_segment == 0 {
+            total += self.first.write_bits(source)?;
+            if total < source.len() {
+                self.current_segment = 1;
+                total += self.second.write_bits(&source[total..])?;
+            }
+            return Ok(total);
+        }
+        self.second.write_bits(source)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::SeekFrom;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn test_read_across_boundary() {
+        let first = BitVec::from_vec(vec![0b11110000]);
+        let second = BitVec::from_vec(vec![0b00001111]);
+        let mut chain = BitCursor::new(first).chain(second);
+
+        chain.bit_seek(SeekFrom::Start(4)).unwrap();
+        let mut read_buf = bitvec![0; 8];
+        assert_eq!(chain.read_bits(&mut read_buf).unwrap(), 8);
+        assert_eq!(read_buf, bits![0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_remaining() {
+        let first = BitVec::from_vec(vec![0u8; 2]);
+        let second = BitVec::from_vec(vec![0u8; 3]);
+        let chain = BitCursor::new(first).chain(second);
+        assert_eq!(chain.remaining(), 40);
+    }
+
+    #[test]
+    fn test_seek_end() {
+        let first = BitVec::from_vec(vec![0b11110000]);
+        let second = BitVec::from_vec(vec![0b00001111]);
+        let mut chain = BitCursor::new(first).chain(second);
+
+        chain.bit_seek(SeekFrom::End(-4)).unwrap();
+        let mut read_buf = bitvec![0; 4];
+        assert_eq!(chain.read_bits(&mut read_buf).unwrap(), 4);
+        assert_eq!(read_buf, bits![1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_seek_past_end_is_clamped() {
+        let first = BitVec::from_vec(vec![0b11110000]);
+        let second = BitVec::from_vec(vec![0b00001111]);
+        let mut chain = BitCursor::new(first).chain(second);
+
+        assert_eq!(chain.bit_seek(SeekFrom::Start(100)).unwrap(), 16);
+        let mut read_buf = bitvec![0; 1];
+        assert_eq!(chain.read_bits(&mut read_buf).unwrap(), 0);
+    }
+}
@@ -159,6 +159,27 @@ where
         self.pos += n as u64;
         Ok(n)
     }
+
+    /// Overrides the default looping implementation: the tail after the
+    /// current position is split once and drained across all of `dests`,
+    /// instead of re-splitting (and re-borrowing) the underlying buffer for
+    /// every destination.
+    fn read_bits_vectored<O: BitStore>(
+        &mut self,
+        dests: &mut [&mut BitSlice<O>],
+    ) -> std::io::Result<usize> {
+        let mut src = BitCursor::split(self).1;
+        let mut total = 0;
+        for dest in dests.iter_mut() {
+            let n = BitRead::read_bits(&mut src, dest)?;
+            total += n;
+            if n < dest.len() {
+                break;
+            }
+        }
+        self.pos += total as u64;
+        Ok(total)
+    }
 }
 
 impl<T> Write for BitCursor<T>
@@ -186,6 +207,27 @@ where
         self.pos += n as u64;
         Ok(n)
     }
+
+    /// Overrides the default looping implementation: the tail after the
+    /// current position is split once and filled across all of `sources`,
+    /// instead of re-splitting (and re-borrowing) the underlying buffer for
+    /// every source.
+    fn write_bits_vectored<O: BitStore>(
+        &mut self,
+        sources: &[&BitSlice<O>],
+    ) -> std::io::Result<usize> {
+        let mut dest = BitCursor::split_mut(self).1;
+        let mut total = 0;
+        for source in sources {
+            let n = BitWrite::write_bits(&mut dest, source)?;
+            total += n;
+            if n < source.len() {
+                break;
+            }
+        }
+        self.pos += total as u64;
+        Ok(total)
+    }
 }
 
 impl<T> LowerHex for BitCursor<T>
@@ -448,4 +490,35 @@ mod test {
             assert_eq!(value, read_buf, "offset {offset}");
         }
     }
+
+    #[test]
+    fn test_write_bits_vectored() {
+        let buf = vec![0u8; 2];
+        let mut cursor = BitCursor::new(buf);
+
+        let a = bits![1, 1, 1, 1];
+        let b = bits![0, 0, 0, 0, 1, 1, 1, 1];
+        assert_eq!(cursor.write_bits_vectored(&[a, b]).unwrap(), 12);
+        assert_eq!(
+            cursor.into_inner().borrow_bits(),
+            bits![1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_read_bits_vectored() {
+        let data = BitVec::from_vec(vec![0b11110000, 0b11110000]);
+        let mut cursor = BitCursor::new(data);
+
+        let mut a = bitvec![0; 4];
+        let mut b = bitvec![0; 12];
+        assert_eq!(
+            cursor
+                .read_bits_vectored(&mut [a.as_mut_bitslice(), b.as_mut_bitslice()])
+                .unwrap(),
+            16
+        );
+        assert_eq!(a, bits![1, 1, 1, 1]);
+        assert_eq!(b[..4], bits![0, 0, 0, 0]);
+    }
 }
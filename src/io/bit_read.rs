@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+/// Bit-level analogue of [`std::io::Read`].
+pub trait BitRead {
+    /// Reads bits into `dest`, returning the number of bits actually read.
+    fn read_bits<O: BitStore>(&mut self, dest: &mut BitSlice<O>) -> std::io::Result<usize>;
+
+    /// Reads into each of `dests` in turn, as if [`BitRead::read_bits`] had
+    /// been called once per slice, summing the bits transferred. Stops
+    /// early (without erroring) once the underlying source is exhausted.
+    ///
+    /// Implementors that can service multiple destinations more cheaply
+    /// than one `read_bits` call apiece (e.g. [`BitCursor`], which can split
+    /// its underlying buffer a single time) should override this.
+    fn read_bits_vectored<O: BitStore>(
+        &mut self,
+        dests: &mut [&mut BitSlice<O>],
+    ) -> std::io::Result<usize> {
+        let mut total = 0;
+        for dest in dests.iter_mut() {
+            let n = self.read_bits(dest)?;
+            total += n;
+            if n < dest.len() {
+                break;
+            }
+        }
+        Ok(total)
+    }
+}
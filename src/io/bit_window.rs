@@ -0,0 +1,229 @@
+use std::io::SeekFrom;
+
+use crate::prelude::*;
+
+/// A bounded sub-cursor over a declared bit range of a [`BitCursor`].
+///
+/// Created by [`BitCursor::window`]. Reads and writes are clamped to
+/// `[start, start + len_bits)`, where `start` is the parent cursor's
+/// position at the time the window was created; seeks to a position outside
+/// `[start, start + len_bits]` (or past the parent's data) fail with
+/// `InvalidInput`. When the window is dropped (or [`BitWindow::finish`] is
+/// called explicitly), the parent cursor's position is advanced to
+/// `start + len_bits` (or to the end of the parent's data, if that comes
+/// first) regardless of how much of the window was actually consumed, so the
+/// caller can resume parsing right after the field. This composes with [`BitCursor::split`] but adds
+/// bounded, length-delimited semantics that a raw split does not.
+pub struct BitWindow<'a, T> {
+    cursor: &'a mut BitCursor<T>,
+    start: u64,
+    end: u64,
+    // The parent buffer's length, in bits, captured at window creation so
+    // `Drop` can clamp to it without needing a `BorrowBits` bound of its own.
+    parent_len: u64,
+    pos: u64,
+}
+
+impl<T> BitCursor<T> {
+    /// Returns a [`BitWindow`] bounding reads/writes/seeks to the next
+    /// `len_bits` bits, starting at the cursor's current position.
+    pub fn window(&mut self, len_bits: u64) -> BitWindow<'_, T>
+    where
+        T: BorrowBits,
+    {
+        let start = self.position();
+        let parent_len = self.get_ref().borrow_bits().len() as u64;
+        BitWindow {
+            cursor: self,
+            start,
+            end: start.saturating_add(len_bits),
+            parent_len,
+            pos: start,
+        }
+    }
+}
+
+impl<T> BitWindow<'_, T> {
+    /// Consumes the window, advancing the parent cursor to the end of the
+    /// window. Equivalent to letting the window drop, but makes the point at
+    /// which parsing resumes explicit at the call site.
+    pub fn finish(self) {}
+
+    /// Returns the length, in bits, of this window.
+    pub fn len(&self) -> u64 {
+        self.end - self.start
+    }
+
+    /// Returns `true` if this window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Drop for BitWindow<'_, T> {
+    fn drop(&mut self) {
+        // `end` may run past the parent buffer if the window was declared
+        // longer than what's actually left (e.g. a corrupted length
+        // prefix); clamp so the parent cursor never ends up parked beyond
+        // its own data, which would panic on its next `read_bits`/`split`.
+        self.cursor.set_position(self.end.min(self.parent_len));
+    }
+}
+
+fn invalid_seek() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "invalid seek to a position outside of the window",
+    )
+}
+
+impl<T> BitSeek for BitWindow<'_, T>
+where
+    T: BorrowBits,
+{
+    fn bit_seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        // `Start`/`Current` are relative to the window, i.e. `Start(0)` is
+        // always the first bit of the window regardless of where it sits in
+        // the parent cursor; `End` is relative to the window's end. All
+        // three resolve to the equivalent absolute parent-cursor position,
+        // which is also what `position()`/the returned value use.
+        let target = match pos {
+            SeekFrom::Start(n) => self.start.checked_add(n).ok_or_else(invalid_seek)?,
+            SeekFrom::End(n) => self.end.checked_add_signed(n).ok_or_else(invalid_seek)?,
+            SeekFrom::Current(n) => self.pos.checked_add_signed(n).ok_or_else(invalid_seek)?,
+        };
+        // `self.end` may itself run past the parent buffer for an oversized
+        // window (see `Drop`'s comment); also reject seeks past the real
+        // data so a later `read_bits`/`write_bits` can't be handed a
+        // position beyond the buffer and panic in `BitCursor::split`.
+        if target < self.start || target > self.end || target > self.parent_len {
+            return Err(invalid_seek());
+        }
+        self.pos = target;
+        Ok(self.pos)
+    }
+}
+
+impl<T> BitRead for BitWindow<'_, T>
+where
+    T: BorrowBits,
+{
+    fn read_bits<O: BitStore>(&mut self, dest: &mut BitSlice<O>) -> std::io::Result<usize> {
+        let available = (self.end - self.pos) as usize;
+        let to_read = dest.len().min(available);
+        self.cursor.set_position(self.pos);
+        let n = self.cursor.read_bits(&mut dest[..to_read])?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T> BitWrite for BitWindow<'_, T>
+where
+    T: BorrowBitsMut,
+    BitCursor<T>: std::io::Write,
+{
+    fn write_bits<O: BitStore>(&mut self, source: &BitSlice<O>) -> std::io::Result<usize> {
+        let available = (self.end - self.pos) as usize;
+        let to_write = source.len().min(available);
+        self.cursor.set_position(self.pos);
+        let n = self.cursor.write_bits(&source[..to_write])?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::SeekFrom;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn test_window_clamps_reads() {
+        let data = BitVec::from_vec(vec![0b11110000, 0b10101010, 0b00001111]);
+        let mut cursor = BitCursor::new(data);
+        cursor.set_position(8);
+
+        {
+            let mut window = cursor.window(8);
+            let mut read_buf = bitvec![0; 16];
+            // Only 8 bits are available in the window, even though 16 were requested.
+            assert_eq!(window.read_bits(&mut read_buf).unwrap(), 8);
+        }
+
+        // The parent cursor resumes right after the window, not wherever the
+        // window's own reads happened to stop.
+        assert_eq!(cursor.position(), 16);
+    }
+
+    #[test]
+    fn test_window_seek_end() {
+        let data = BitVec::from_vec(vec![0u8; 4]);
+        let mut cursor = BitCursor::new(data);
+        cursor.set_position(4);
+
+        let mut window = cursor.window(8);
+        assert_eq!(window.bit_seek(SeekFrom::End(0)).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_window_seek_out_of_bounds_rejected() {
+        let data = BitVec::from_vec(vec![0u8; 4]);
+        let mut cursor = BitCursor::new(data);
+        cursor.set_position(4);
+
+        let mut window = cursor.window(8);
+        // 9 bits is past the window's 8-bit length.
+        assert!(window.bit_seek(SeekFrom::Start(9)).is_err());
+        // Backing up 5 from `Current` (which starts at the window's bit 0)
+        // would land before the window's start.
+        assert!(window.bit_seek(SeekFrom::Current(-5)).is_err());
+    }
+
+    #[test]
+    fn test_oversized_window_clamps_parent_position_on_drop() {
+        let data = BitVec::from_vec(vec![0u8; 4]); // 32 bits total
+        let mut cursor = BitCursor::new(data);
+        cursor.set_position(28);
+
+        {
+            // Declares 8 bits but only 4 remain; the window itself clamps
+            // reads, but the parent's post-drop position must also clamp,
+            // not jump to the declared (out-of-range) end.
+            let _window = cursor.window(8);
+        }
+
+        assert_eq!(cursor.position(), 32);
+        // The parent cursor must still be usable after an oversized window.
+        let mut read_buf = bitvec![0; 0];
+        assert!(cursor.read_bits(&mut read_buf).is_ok());
+    }
+
+    #[test]
+    fn test_oversized_window_seek_past_buffer_rejected() {
+        let data = BitVec::from_vec(vec![0u8; 4]); // 32 bits total
+        let mut cursor = BitCursor::new(data);
+        cursor.set_position(28);
+
+        // Declares 8 bits but only 4 remain; seeking to the window's
+        // (out-of-range) declared end must not succeed, since a
+        // subsequent read/write at that position would panic.
+        let mut window = cursor.window(8);
+        assert!(window.bit_seek(SeekFrom::End(0)).is_err());
+        assert!(window.bit_seek(SeekFrom::Start(0)).is_ok());
+    }
+
+    #[test]
+    fn test_window_seek_start_is_relative_to_window() {
+        let data = BitVec::from_vec(vec![0u8; 4]);
+        let mut cursor = BitCursor::new(data);
+        cursor.set_position(4);
+
+        // `Start(0)` should always mean "the first bit of the window", not
+        // "the first bit of the parent buffer".
+        let mut window = cursor.window(8);
+        assert_eq!(window.bit_seek(SeekFrom::Start(0)).unwrap(), 4);
+        assert_eq!(window.bit_seek(SeekFrom::Start(8)).unwrap(), 12);
+    }
+}
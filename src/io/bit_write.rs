@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// Bit-level analogue of [`std::io::Write`].
+pub trait BitWrite {
+    /// Writes bits from `source`, returning the number of bits actually
+    /// written.
+    fn write_bits<O: BitStore>(&mut self, source: &BitSlice<O>) -> std::io::Result<usize>;
+
+    /// Writes the concatenation of `sources` in turn, as if
+    /// [`BitWrite::write_bits`] had been called once per slice, summing the
+    /// bits transferred. Stops early (without erroring) once the
+    /// underlying destination is exhausted.
+    ///
+    /// Implementors that can service multiple sources more cheaply than one
+    /// `write_bits` call apiece (e.g. [`BitCursor`], which can split its
+    /// underlying buffer a single time) should override this.
+    fn write_bits_vectored<O: BitStore>(
+        &mut self,
+        sources: &[&BitSlice<O>],
+    ) -> std::io::Result<usize> {
+        let mut total = 0;
+        for source in sources {
+            let n = self.write_bits(source)?;
+            total += n;
+            if n < source.len() {
+                break;
+            }
+        }
+        Ok(total)
+    }
+}